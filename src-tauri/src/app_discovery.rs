@@ -0,0 +1,537 @@
+// Cross-platform desktop application discovery.
+//
+// Enumerates installed applications so the NYX shell can present a real
+// launcher instead of a handful of hardcoded shortcuts.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DesktopApp {
+    pub name: String,
+    pub path: String,
+    pub icon: String,
+}
+
+/// Caches the most recent full discovery result so other subsystems (the
+/// tray's recent-apps tracking) can look up an app's name/icon from just the
+/// launch path it was spawned with, without re-running discovery.
+pub struct DiscoveredAppsState(Mutex<Vec<DesktopApp>>);
+
+impl DiscoveredAppsState {
+    pub fn new() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+
+    pub fn find_by_path(&self, path: &str) -> Option<DesktopApp> {
+        self.0
+            .lock()
+            .expect("discovered apps state poisoned")
+            .iter()
+            .find(|app| app.path == path)
+            .cloned()
+    }
+}
+
+#[tauri::command]
+pub async fn get_desktop_apps(state: tauri::State<'_, DiscoveredAppsState>) -> Result<Vec<DesktopApp>, String> {
+    let apps = tokio::task::spawn_blocking(discover_apps)
+        .await
+        .map_err(|e| format!("App discovery task panicked: {}", e))?;
+    *state.0.lock().expect("discovered apps state poisoned") = apps.clone();
+    Ok(apps)
+}
+
+fn discover_apps() -> Vec<DesktopApp> {
+    #[cfg(target_os = "linux")]
+    {
+        linux::discover()
+    }
+    #[cfg(target_os = "windows")]
+    {
+        windows::discover()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        macos::discover()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::DesktopApp;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    pub fn discover() -> Vec<DesktopApp> {
+        // The user's local dir takes precedence over the system-wide one, so
+        // it's scanned first; `seen_ids` then makes the system-wide copy of
+        // an overridden `.desktop` file a no-op instead of a duplicate entry.
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        if let Some(home) = std::env::var_os("HOME") {
+            dirs.push(PathBuf::from(home).join(".local/share/applications"));
+        }
+        dirs.push(PathBuf::from("/usr/share/applications"));
+
+        let mut seen_ids = std::collections::HashSet::new();
+        let mut apps = Vec::new();
+        for dir in dirs {
+            collect_from_dir(&dir, &mut seen_ids, &mut apps);
+        }
+
+        apps.sort_by(|a, b| a.name.cmp(&b.name));
+        apps
+    }
+
+    fn collect_from_dir(dir: &Path, seen_ids: &mut std::collections::HashSet<String>, apps: &mut Vec<DesktopApp>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("desktop") {
+                continue;
+            }
+            // The desktop-id (filename sans extension) is what XDG uses to
+            // decide whether two `.desktop` files refer to the same app.
+            let Some(desktop_id) = path.file_stem().and_then(|s| s.to_str()).map(str::to_string) else {
+                continue;
+            };
+            if !seen_ids.insert(desktop_id) {
+                continue;
+            }
+            if let Ok(contents) = fs::read_to_string(&path) {
+                if let Some(app) = parse_desktop_entry(&contents) {
+                    apps.push(app);
+                }
+            }
+        }
+    }
+
+    fn parse_desktop_entry(contents: &str) -> Option<DesktopApp> {
+        let mut in_desktop_entry = false;
+        let mut name = None;
+        let mut exec = None;
+        let mut icon = None;
+        let mut no_display = false;
+        let mut hidden = false;
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_desktop_entry = line == "[Desktop Entry]";
+                continue;
+            }
+            if !in_desktop_entry {
+                continue;
+            }
+
+            if let Some(value) = line.strip_prefix("Name=") {
+                name.get_or_insert(value.to_string());
+            } else if let Some(value) = line.strip_prefix("Exec=") {
+                exec.get_or_insert(strip_field_codes(value));
+            } else if let Some(value) = line.strip_prefix("Icon=") {
+                icon.get_or_insert(value.to_string());
+            } else if let Some(value) = line.strip_prefix("NoDisplay=") {
+                no_display = value.eq_ignore_ascii_case("true");
+            } else if let Some(value) = line.strip_prefix("Hidden=") {
+                hidden = value.eq_ignore_ascii_case("true");
+            }
+        }
+
+        if no_display || hidden {
+            return None;
+        }
+
+        Some(DesktopApp {
+            name: name?,
+            path: exec?,
+            icon: icon.unwrap_or_else(|| "application-x-executable".to_string()),
+        })
+    }
+
+    fn strip_field_codes(exec: &str) -> String {
+        // Drop %f, %F, %u, %U, %i, %c, %k and similar desktop-entry field codes.
+        let mut result = String::with_capacity(exec.len());
+        let mut chars = exec.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '%' {
+                chars.next();
+                continue;
+            }
+            result.push(c);
+        }
+        result.trim().to_string()
+    }
+}
+
+/// Splits a command line into a program and its arguments, honoring
+/// single/double-quoted segments and backslash escapes the way a desktop
+/// entry's `Exec` key (or a shell) would. Most real-world `Exec` values
+/// carry flags (`code --unity-launch`, `steam -silent`), so callers must
+/// split before handing `path` to `Command::new`.
+pub fn split_command_line(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quote: Option<char> = None;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                } else if c == '\\' && q == '"' {
+                    if let Some(next) = chars.next() {
+                        current.push(next);
+                    }
+                } else {
+                    current.push(c);
+                }
+            }
+            None => match c {
+                '\'' | '"' => {
+                    quote = Some(c);
+                    in_token = true;
+                }
+                c if c.is_whitespace() => {
+                    if in_token {
+                        tokens.push(std::mem::take(&mut current));
+                        in_token = false;
+                    }
+                }
+                '\\' => {
+                    if let Some(next) = chars.next() {
+                        current.push(next);
+                        in_token = true;
+                    }
+                }
+                c => {
+                    current.push(c);
+                    in_token = true;
+                }
+            },
+        }
+    }
+
+    if in_token {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod split_command_line_tests {
+    use super::split_command_line;
+
+    #[test]
+    fn splits_on_unquoted_whitespace() {
+        assert_eq!(split_command_line("code --unity-launch"), vec!["code", "--unity-launch"]);
+    }
+
+    #[test]
+    fn single_quoted_windows_path_survives_intact() {
+        // Regression test: a `.lnk` target wrapped in single quotes by
+        // `windows::quote_if_needed` must come back as one token with its
+        // backslashes untouched, even though it contains spaces.
+        let input = r"'C:\Program Files\Google\Chrome\Application\chrome.exe'";
+        assert_eq!(
+            split_command_line(input),
+            vec![r"C:\Program Files\Google\Chrome\Application\chrome.exe"]
+        );
+    }
+
+    #[test]
+    fn double_quotes_interpret_backslash_as_escape() {
+        assert_eq!(split_command_line(r#""a\ b""#), vec!["a b"]);
+    }
+
+    #[test]
+    fn unquoted_backslash_escapes_next_char() {
+        assert_eq!(split_command_line(r"a\ b"), vec!["a b"]);
+    }
+
+    #[test]
+    fn empty_input_yields_no_tokens() {
+        assert!(split_command_line("").is_empty());
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::DesktopApp;
+    use std::path::{Path, PathBuf};
+
+    pub fn discover() -> Vec<DesktopApp> {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        if let Some(programdata) = std::env::var_os("PROGRAMDATA") {
+            dirs.push(PathBuf::from(programdata).join("Microsoft/Windows/Start Menu/Programs"));
+        }
+        if let Some(appdata) = std::env::var_os("APPDATA") {
+            dirs.push(PathBuf::from(appdata).join("Microsoft/Windows/Start Menu/Programs"));
+        }
+
+        let mut apps = Vec::new();
+        for dir in dirs {
+            walk_dir(&dir, &mut apps);
+        }
+
+        apps.sort_by(|a, b| a.name.cmp(&b.name));
+        apps.dedup_by(|a, b| a.path == b.path);
+        apps
+    }
+
+    fn walk_dir(dir: &Path, apps: &mut Vec<DesktopApp>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                walk_dir(&path, apps);
+                continue;
+            }
+            if path.extension().and_then(|e| e.to_str()) != Some("lnk") {
+                continue;
+            }
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            // `Command` can't execute a `.lnk` shortcut file itself; resolve
+            // it to the target it points at.
+            let Some(target) = resolve_shortcut_target(&path) else {
+                continue;
+            };
+            apps.push(DesktopApp {
+                name: stem.to_string(),
+                // Most installs live under `C:\Program Files\...`; quote the
+                // path so `split_command_line` treats it as one token
+                // instead of splitting on the space.
+                path: quote_if_needed(&target),
+                icon: "application".to_string(),
+            });
+        }
+    }
+
+    /// Extracts the `LocalBasePath` field from a `.lnk` file's `LinkInfo`
+    /// structure per the MS-SHLLINK binary format, i.e. the absolute path of
+    /// the shortcut's target. Returns `None` for shortcuts that only carry a
+    /// target ID list (e.g. some Store apps) rather than a local path.
+    fn resolve_shortcut_target(path: &Path) -> Option<String> {
+        let data = std::fs::read(path).ok()?;
+        if data.len() < 76 || &data[0..4] != [0x4C, 0x00, 0x00, 0x00] {
+            return None;
+        }
+
+        const HAS_LINK_TARGET_ID_LIST: u32 = 0x0000_0001;
+        const HAS_LINK_INFO: u32 = 0x0000_0002;
+
+        let link_flags = u32::from_le_bytes(data.get(20..24)?.try_into().ok()?);
+        let mut offset = 76usize;
+
+        if link_flags & HAS_LINK_TARGET_ID_LIST != 0 {
+            let id_list_size = u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?) as usize;
+            offset += 2 + id_list_size;
+        }
+
+        if link_flags & HAS_LINK_INFO == 0 {
+            return None;
+        }
+
+        let link_info_start = offset;
+        let link_info_size = u32::from_le_bytes(data.get(link_info_start..link_info_start + 4)?.try_into().ok()?) as usize;
+        let local_base_path_offset =
+            u32::from_le_bytes(data.get(link_info_start + 16..link_info_start + 20)?.try_into().ok()?) as usize;
+
+        if local_base_path_offset == 0 {
+            return None;
+        }
+
+        let path_start = link_info_start + local_base_path_offset;
+        let link_info_end = (link_info_start + link_info_size).min(data.len());
+        let path_end = path_start + data.get(path_start..link_info_end)?.iter().position(|&b| b == 0)?;
+
+        String::from_utf8(data[path_start..path_end].to_vec()).ok()
+    }
+
+    /// Wraps `path` in single quotes if it contains whitespace, so it
+    /// survives `split_command_line`'s tokenizing as a single argv entry.
+    /// Single quotes (rather than double) are used deliberately: inside
+    /// double quotes the tokenizer treats `\` as an escape character, which
+    /// would eat the backslashes in a Windows path like
+    /// `C:\Program Files\...`.
+    fn quote_if_needed(path: &str) -> String {
+        if path.contains(char::is_whitespace) {
+            format!("'{}'", path)
+        } else {
+            path.to_string()
+        }
+    }
+
+    #[cfg(test)]
+    mod resolve_shortcut_target_tests {
+        use super::resolve_shortcut_target;
+
+        /// Builds a minimal but spec-valid `.lnk` file: a 76-byte
+        /// `ShellLinkHeader` (`HasLinkInfo` only, no target ID list) followed
+        /// by a `LinkInfo` structure whose `LocalBasePath` is `target`.
+        fn build_lnk(target: &str) -> Vec<u8> {
+            const HAS_LINK_INFO: u32 = 0x0000_0002;
+            const LOCAL_BASE_PATH_OFFSET: u32 = 28;
+
+            let mut header = vec![0u8; 76];
+            header[0..4].copy_from_slice(&76u32.to_le_bytes());
+            header[20..24].copy_from_slice(&HAS_LINK_INFO.to_le_bytes());
+
+            let link_info_size = LOCAL_BASE_PATH_OFFSET + target.len() as u32 + 1;
+            let mut link_info = vec![0u8; LOCAL_BASE_PATH_OFFSET as usize];
+            link_info[0..4].copy_from_slice(&link_info_size.to_le_bytes());
+            link_info[4..8].copy_from_slice(&LOCAL_BASE_PATH_OFFSET.to_le_bytes()); // LinkInfoHeaderSize
+            link_info[8..12].copy_from_slice(&1u32.to_le_bytes()); // VolumeIDAndLocalBasePath flag
+            link_info[16..20].copy_from_slice(&LOCAL_BASE_PATH_OFFSET.to_le_bytes()); // LocalBasePathOffset
+            link_info.extend_from_slice(target.as_bytes());
+            link_info.push(0);
+
+            header.extend_from_slice(&link_info);
+            header
+        }
+
+        fn write_temp_lnk(name: &str, data: &[u8]) -> std::path::PathBuf {
+            let path = std::env::temp_dir().join(name);
+            std::fs::write(&path, data).expect("failed to write temp .lnk fixture");
+            path
+        }
+
+        #[test]
+        fn resolves_local_base_path() {
+            let path = write_temp_lnk(
+                "nyx-resolve-shortcut-target-test.lnk",
+                &build_lnk(r"C:\Program Files\Example\app.exe"),
+            );
+            let target = resolve_shortcut_target(&path);
+            std::fs::remove_file(&path).ok();
+            assert_eq!(target.as_deref(), Some(r"C:\Program Files\Example\app.exe"));
+        }
+
+        #[test]
+        fn rejects_data_too_short_for_header() {
+            let path = write_temp_lnk("nyx-resolve-shortcut-target-test-short.lnk", &[0u8; 10]);
+            let target = resolve_shortcut_target(&path);
+            std::fs::remove_file(&path).ok();
+            assert_eq!(target, None);
+        }
+
+        #[test]
+        fn rejects_missing_link_info() {
+            let mut header = vec![0u8; 76];
+            header[0..4].copy_from_slice(&76u32.to_le_bytes());
+            // LinkFlags left at 0: neither HasLinkTargetIDList nor HasLinkInfo set.
+            let path = write_temp_lnk("nyx-resolve-shortcut-target-test-no-info.lnk", &header);
+            let target = resolve_shortcut_target(&path);
+            std::fs::remove_file(&path).ok();
+            assert_eq!(target, None);
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use super::DesktopApp;
+    use serde::Deserialize;
+    use std::path::{Path, PathBuf};
+
+    #[derive(Debug, Deserialize)]
+    struct InfoPlist {
+        #[serde(rename = "CFBundleName")]
+        bundle_name: Option<String>,
+        #[serde(rename = "CFBundleDisplayName")]
+        display_name: Option<String>,
+        #[serde(rename = "CFBundleIconFile")]
+        icon_file: Option<String>,
+        #[serde(rename = "CFBundleExecutable")]
+        executable: Option<String>,
+    }
+
+    pub fn discover() -> Vec<DesktopApp> {
+        let mut dirs: Vec<PathBuf> = vec![PathBuf::from("/Applications")];
+        if let Some(home) = std::env::var_os("HOME") {
+            dirs.push(PathBuf::from(home).join("Applications"));
+        }
+
+        let mut apps = Vec::new();
+        for dir in dirs {
+            collect_from_dir(&dir, &mut apps);
+        }
+
+        apps.sort_by(|a, b| a.name.cmp(&b.name));
+        apps
+    }
+
+    fn collect_from_dir(dir: &Path, apps: &mut Vec<DesktopApp>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("app") {
+                continue;
+            }
+            if let Some(app) = parse_bundle(&path) {
+                apps.push(app);
+            }
+        }
+    }
+
+    /// Reads the bundle's `Info.plist` for its display name, icon file, and
+    /// executable, falling back to the `.app` filename when a bundle is
+    /// malformed.
+    fn parse_bundle(bundle_path: &Path) -> Option<DesktopApp> {
+        let fallback_name = bundle_path.file_stem()?.to_str()?.to_string();
+
+        let info: InfoPlist = plist::from_file(bundle_path.join("Contents/Info.plist")).unwrap_or(InfoPlist {
+            bundle_name: None,
+            display_name: None,
+            icon_file: None,
+            executable: None,
+        });
+
+        let name = info.display_name.or(info.bundle_name).unwrap_or_else(|| fallback_name.clone());
+        let icon = info
+            .icon_file
+            .map(|icon_file| {
+                // CFBundleIconFile conventionally omits the .icns extension.
+                if icon_file.ends_with(".icns") {
+                    icon_file
+                } else {
+                    format!("{icon_file}.icns")
+                }
+            })
+            .unwrap_or_else(|| "application".to_string());
+
+        // `Command` can't exec a `.app` bundle directory; the real binary
+        // lives at Contents/MacOS/<CFBundleExecutable>. Fall back to the
+        // bundle's own name, which is the executable name by convention
+        // when Info.plist omits CFBundleExecutable.
+        let executable = info.executable.unwrap_or(fallback_name);
+        let executable_path = bundle_path.join("Contents/MacOS").join(executable);
+        if !executable_path.is_file() {
+            return None;
+        }
+
+        Some(DesktopApp {
+            name,
+            path: executable_path.to_string_lossy().to_string(),
+            icon,
+        })
+    }
+}
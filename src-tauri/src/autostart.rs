@@ -0,0 +1,31 @@
+// Start-at-login toggle. NYX OS runs as a fullscreen desktop-replacement
+// shell, so booting automatically is a natural choice; these commands let the
+// settings UI read and change the OS-level autostart state. Nothing here
+// enables autostart on its own.
+
+use auto_launch::AutoLaunch;
+
+fn auto_launch() -> Result<AutoLaunch, String> {
+    let exe_path = std::env::current_exe().map_err(|e| format!("Failed to resolve executable path: {}", e))?;
+    let exe_path = exe_path
+        .to_str()
+        .ok_or_else(|| "Executable path is not valid UTF-8".to_string())?;
+
+    Ok(AutoLaunch::new("NYX OS", exe_path, &[] as &[&str]))
+}
+
+#[tauri::command]
+pub async fn set_autostart(enabled: bool) -> Result<(), String> {
+    let auto = auto_launch()?;
+    if enabled {
+        auto.enable().map_err(|e| format!("Failed to enable autostart: {}", e))
+    } else {
+        auto.disable().map_err(|e| format!("Failed to disable autostart: {}", e))
+    }
+}
+
+#[tauri::command]
+pub async fn get_autostart() -> Result<bool, String> {
+    let auto = auto_launch()?;
+    auto.is_enabled().map_err(|e| format!("Failed to check autostart state: {}", e))
+}
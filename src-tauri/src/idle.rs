@@ -0,0 +1,105 @@
+// Activity-based auto-hide: hides every NYX window (not just `main`) after a
+// configurable period of inactivity, so the shell can behave like a
+// lock-screen for an "OS shell" whose spawned windows otherwise float above
+// every virtual desktop/Space.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Manager};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct IdleState {
+    last_activity: Mutex<Instant>,
+    timeout: Mutex<Option<Duration>>,
+    /// Labels of the windows the watcher itself hid, so a later "wake" can
+    /// restore exactly those rather than just `main`.
+    hidden_by_idle: Mutex<Vec<String>>,
+}
+
+impl IdleState {
+    pub fn new() -> Self {
+        Self {
+            last_activity: Mutex::new(Instant::now()),
+            timeout: Mutex::new(None),
+            hidden_by_idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn record_activity(&self) {
+        *self.last_activity.lock().expect("idle state poisoned") = Instant::now();
+    }
+}
+
+#[tauri::command]
+pub async fn record_activity(state: tauri::State<'_, IdleState>) -> Result<(), String> {
+    state.record_activity();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_idle_timeout(state: tauri::State<'_, IdleState>, seconds: Option<u64>) -> Result<(), String> {
+    let mut timeout = state.timeout.lock().map_err(|e| e.to_string())?;
+    *timeout = seconds.map(Duration::from_secs);
+    drop(timeout);
+    state.record_activity();
+    Ok(())
+}
+
+/// Re-shows every window the idle watcher hid and resets the clock. Call
+/// this from every "bring NYX back" path — tray show/toggle, `show_window`,
+/// `focus_native_window`, second-instance relaunch — so a window idle-hid
+/// isn't stranded behind a `focus_native_window` call by its exact label.
+pub fn wake(app_handle: &AppHandle) {
+    let state = app_handle.state::<IdleState>();
+    let labels = std::mem::take(&mut *state.hidden_by_idle.lock().expect("idle state poisoned"));
+    for label in labels {
+        if let Some(window) = app_handle.get_window(&label) {
+            let _ = window.show();
+            if label == "main" {
+                crate::tray::set_toggle_title(app_handle, true);
+            }
+        }
+    }
+    state.record_activity();
+}
+
+/// Starts the background idle-timeout watcher. Call once from `setup()`.
+pub fn start_watcher(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+
+            let state = app_handle.state::<IdleState>();
+            let timeout = *state.timeout.lock().expect("idle state poisoned");
+            let Some(timeout) = timeout else {
+                continue;
+            };
+
+            let elapsed = state.last_activity.lock().expect("idle state poisoned").elapsed();
+            if elapsed < timeout {
+                continue;
+            }
+
+            let mut hidden = Vec::new();
+            for (label, window) in app_handle.windows() {
+                if window.is_visible().unwrap_or(false) {
+                    let _ = window.hide();
+                    if label == "main" {
+                        crate::tray::set_toggle_title(&app_handle, false);
+                    }
+                    hidden.push(label);
+                }
+            }
+            if !hidden.is_empty() {
+                *state.hidden_by_idle.lock().expect("idle state poisoned") = hidden;
+                let _ = app_handle.emit_all("nyx:idle-timeout", ());
+            }
+
+            // Don't re-fire every tick once hidden: reset the clock until
+            // activity (or a re-show) starts it counting again.
+            state.record_activity();
+        }
+    });
+}
@@ -1,7 +1,22 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::{CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu, SystemTrayMenuItem, WindowBuilder, WindowUrl};
+use tauri::{Manager, SystemTray, SystemTrayEvent, WindowBuilder, WindowUrl};
+
+mod app_discovery;
+mod autostart;
+mod idle;
+mod performance;
+mod recent_apps;
+mod shortcuts;
+mod tray;
+
+use app_discovery::{get_desktop_apps, DiscoveredAppsState};
+use autostart::{get_autostart, set_autostart};
+use idle::{record_activity, set_idle_timeout, IdleState};
+use performance::{get_performance_info, PerformanceState};
+use recent_apps::RecentAppsState;
+use shortcuts::{list_shortcuts, rebind_shortcut, ShortcutState};
 
 // System information commands
 #[tauri::command]
@@ -43,72 +58,108 @@ async fn maximize_window(window: tauri::Window) -> Result<(), String> {
 
 #[tauri::command]
 async fn hide_window(window: tauri::Window) -> Result<(), String> {
-    window.hide().map_err(|e| e.to_string())
+    window.hide().map_err(|e| e.to_string())?;
+    tray::set_toggle_title(&window.app_handle(), false);
+    Ok(())
 }
 
 #[tauri::command]
 async fn show_window(window: tauri::Window) -> Result<(), String> {
-    window.show().map_err(|e| e.to_string())
+    window.show().map_err(|e| e.to_string())?;
+    tray::set_toggle_title(&window.app_handle(), true);
+    idle::wake(&window.app_handle());
+    Ok(())
 }
 
 // App launching commands
-#[tauri::command]
-async fn launch_external_app(app_path: String) -> Result<(), String> {
-    std::process::Command::new(&app_path)
+
+/// Splits `command_line` into a program and args (honoring quoting) and
+/// spawns it. Shared by `launch_and_track` so there's one place that knows
+/// how to run an app-discovery `path`, which is a full command line rather
+/// than a bare executable.
+fn spawn_app(command_line: &str) -> Result<(), String> {
+    let mut tokens = app_discovery::split_command_line(command_line).into_iter();
+    let program = tokens.next().ok_or_else(|| "Empty command".to_string())?;
+    std::process::Command::new(program)
+        .args(tokens)
         .spawn()
         .map_err(|e| format!("Failed to launch app: {}", e))?;
     Ok(())
 }
 
-// File system commands
-#[tauri::command]
-async fn get_desktop_apps() -> Result<Vec<serde_json::Value>, String> {
-    // This is a placeholder - you'd implement actual app discovery based on OS
-    let apps = vec![
-        serde_json::json!({
-            "name": "File Manager",
-            "path": if cfg!(windows) { "explorer.exe" } else { "nautilus" },
-            "icon": "folder"
-        }),
-        serde_json::json!({
-            "name": "Terminal", 
-            "path": if cfg!(windows) { "cmd.exe" } else { "gnome-terminal" },
-            "icon": "terminal"
-        }),
-        serde_json::json!({
-            "name": "Web Browser",
-            "path": if cfg!(windows) { "msedge.exe" } else { "firefox" },
-            "icon": "globe"
-        })
-    ];
-    
-    Ok(apps)
+fn main_window_visible(app_handle: &tauri::AppHandle) -> bool {
+    app_handle
+        .get_window("main")
+        .and_then(|w| w.is_visible().ok())
+        .unwrap_or(true)
+}
+
+/// Spawns `command_line` and, if it matches a previously discovered app,
+/// bumps it to the front of the recent-apps list and refreshes the tray's
+/// "Launch" submenu. Shared by the `launch_external_app` command and the
+/// tray's own "Launch" submenu click handler.
+fn launch_and_track(app_handle: &tauri::AppHandle, command_line: &str) -> Result<(), String> {
+    spawn_app(command_line)?;
+
+    let discovered = app_handle.state::<DiscoveredAppsState>();
+    if let Some(app) = discovered.find_by_path(command_line) {
+        let recent = recent_apps::record_launch(app_handle, app);
+        tray::refresh_apps_menu(app_handle, main_window_visible(app_handle), &recent);
+    }
+    Ok(())
 }
 
-// Performance monitoring
 #[tauri::command]
-async fn get_performance_info() -> Result<serde_json::Value, String> {
-    // Basic performance info - you could expand this with system metrics
-    let perf_info = serde_json::json!({
-        "memory_usage": "Unknown", // You'd implement actual memory monitoring
-        "cpu_usage": "Unknown",    // You'd implement actual CPU monitoring
-        "timestamp": chrono::Utc::now().timestamp()
-    });
-    
-    Ok(perf_info)
+async fn launch_external_app(app_handle: tauri::AppHandle, app_path: String) -> Result<(), String> {
+    launch_and_track(&app_handle, &app_path)
 }
 
 // Native window creation and management
+#[derive(serde::Deserialize)]
+struct NativeWindowOptions {
+    label: String,
+    title: String,
+    width: Option<f64>,
+    height: Option<f64>,
+    #[serde(default)]
+    decorations: Option<bool>,
+    #[serde(default)]
+    resizable: Option<bool>,
+    #[serde(default)]
+    transparent: Option<bool>,
+    #[serde(default)]
+    always_on_top: Option<bool>,
+    /// Lets the window float across every virtual desktop/Space, for NYX
+    /// windows that should overlay the host OS shell.
+    #[serde(default)]
+    visible_on_all_workspaces: Option<bool>,
+}
+
 #[tauri::command]
-async fn create_native_window(app_handle: tauri::AppHandle, label: String, title: String, width: Option<f64>, height: Option<f64>) -> Result<(), String> {
+async fn create_native_window(app_handle: tauri::AppHandle, options: NativeWindowOptions) -> Result<(), String> {
     // Create a new native window that loads the same app bundle. Frontend can detect the label to render a specific UI state.
     let url = WindowUrl::App("index.html".into());
 
-    let mut builder = WindowBuilder::new(&app_handle, label.clone(), url).title(&title);
+    let mut builder = WindowBuilder::new(&app_handle, options.label.clone(), url).title(&options.title);
 
-    if let (Some(w), Some(h)) = (width, height) {
+    if let (Some(w), Some(h)) = (options.width, options.height) {
         // inner_size expects logical size in many versions; try to call with f64 values
-        let _ = builder = builder.inner_size(w, h);
+        builder = builder.inner_size(w, h);
+    }
+    if let Some(decorations) = options.decorations {
+        builder = builder.decorations(decorations);
+    }
+    if let Some(resizable) = options.resizable {
+        builder = builder.resizable(resizable);
+    }
+    if let Some(transparent) = options.transparent {
+        builder = builder.transparent(transparent);
+    }
+    if let Some(always_on_top) = options.always_on_top {
+        builder = builder.always_on_top(always_on_top);
+    }
+    if let Some(visible_on_all_workspaces) = options.visible_on_all_workspaces {
+        builder = builder.visible_on_all_workspaces(visible_on_all_workspaces);
     }
 
     builder
@@ -117,7 +168,7 @@ async fn create_native_window(app_handle: tauri::AppHandle, label: String, title
         .map_err(|e| format!("Failed to create window: {}", e))?;
 
     // Notify frontend that a native window was created
-    app_handle.emit_all("nyx:native-window-created", label).map_err(|e| e.to_string())?;
+    app_handle.emit_all("nyx:native-window-created", options.label).map_err(|e| e.to_string())?;
     Ok(())
 }
 
@@ -126,6 +177,7 @@ async fn focus_native_window(app_handle: tauri::AppHandle, label: String) -> Res
     if let Some(window) = app_handle.get_window(&label) {
         window.show().map_err(|e| e.to_string())?;
         window.set_focus().map_err(|e| e.to_string())?;
+        idle::wake(&app_handle);
         return Ok(());
     }
     Err("Window not found".into())
@@ -140,40 +192,29 @@ async fn close_native_window(app_handle: tauri::AppHandle, label: String) -> Res
     Err("Window not found".into())
 }
 
-// Global shortcut registration (dynamic) - uses Tauri's GlobalShortcutManager
-#[tauri::command]
-async fn register_global_shortcut(app_handle: tauri::AppHandle, accelerator: String) -> Result<(), String> {
-    let manager = app_handle.global_shortcut_manager();
-    let handle = app_handle.clone();
-    manager.register(&accelerator, move || {
-        // When shortcut is triggered, emit an event to the frontend
-        let _ = handle.emit_all("nyx:global-shortcut", accelerator.clone());
-    }).map_err(|e| format!("Failed to register shortcut: {}", e))?;
-    Ok(())
-}
-
-#[tauri::command]
-async fn unregister_global_shortcut(app_handle: tauri::AppHandle, accelerator: String) -> Result<(), String> {
-    let manager = app_handle.global_shortcut_manager();
-    manager.unregister(&accelerator).map_err(|e| format!("Failed to unregister shortcut: {}", e))?;
-    Ok(())
-}
-
 fn main() {
-    // Create system tray
-    let quit = CustomMenuItem::new("quit".to_string(), "Quit NYX OS");
-    let show = CustomMenuItem::new("show".to_string(), "Show NYX OS");
-    let hide = CustomMenuItem::new("hide".to_string(), "Hide NYX OS");
-    
-    let tray_menu = SystemTrayMenu::new()
-        .add_item(show)
-        .add_item(hide)
-        .add_native_item(SystemTrayMenuItem::Separator)
-        .add_item(quit);
-
-    let system_tray = SystemTray::new().with_menu(tray_menu);
+    // The main window starts visible (fullscreen), so the toggle item starts
+    // as "Hide NYX OS"; the "Launch" submenu is seeded with the persisted
+    // recent-apps list in setup().
+    let system_tray = SystemTray::new().with_menu(tray::build_menu(true, &[]));
 
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            // A second launch happened; bring the existing window forward instead
+            // of letting a new process spawn duplicate windows/trays.
+            if let Some(window) = app.get_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+                tray::set_toggle_title(app, true);
+                idle::wake(app);
+            }
+            let _ = app.emit_all("nyx:second-instance", serde_json::json!({ "argv": argv, "cwd": cwd }));
+        }))
+        .manage(PerformanceState::new())
+        .manage(ShortcutState::new())
+        .manage(IdleState::new())
+        .manage(DiscoveredAppsState::new())
+        .manage(RecentAppsState::new())
         .system_tray(system_tray)
         .on_system_tray_event(|app, event| match event {
             SystemTrayEvent::LeftClick { position: _, size: _, .. } => {
@@ -181,24 +222,32 @@ fn main() {
                 if let Some(window) = app.get_window("main") {
                     let _ = window.show();
                     let _ = window.set_focus();
+                    tray::set_toggle_title(app, true);
+                    idle::wake(app);
                 }
             }
             SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
-                "quit" => {
+                tray::QUIT_ID => {
                     std::process::exit(0);
                 }
-                "show" => {
+                tray::TOGGLE_VISIBILITY_ID => {
                     if let Some(window) = app.get_window("main") {
-                        let _ = window.show();
-                        let _ = window.set_focus();
+                        let now_visible = !window.is_visible().unwrap_or(true);
+                        if now_visible {
+                            let _ = window.show();
+                            let _ = window.set_focus();
+                            idle::wake(app);
+                        } else {
+                            let _ = window.hide();
+                        }
+                        tray::set_toggle_title(app, now_visible);
                     }
                 }
-                "hide" => {
-                    if let Some(window) = app.get_window("main") {
-                        let _ = window.hide();
+                id => {
+                    if let Some(app_path) = tray::app_path_from_menu_id(id) {
+                        let _ = launch_and_track(app, app_path);
                     }
                 }
-                _ => {}
             },
             _ => {}
         })
@@ -216,8 +265,12 @@ fn main() {
             create_native_window,
             focus_native_window,
             close_native_window,
-            register_global_shortcut,
-            unregister_global_shortcut
+            list_shortcuts,
+            rebind_shortcut,
+            record_activity,
+            set_idle_timeout,
+            set_autostart,
+            get_autostart
         ])
         .setup(|app| {
             let main_window = app.get_window("main").unwrap();
@@ -228,12 +281,33 @@ fn main() {
             // Start in fullscreen mode for that OS experience
             let _ = main_window.set_fullscreen(true);
 
-            // Register a convenient default global shortcut to create a new native window: CmdOrCtrl+Shift+N
-            let gsm = app.global_shortcut_manager();
-            let handle = app.handle();
-            let _ = gsm.register("CmdOrCtrl+Shift+N", move || {
-                // When triggered, emit an event so frontend may open a managed app or create a window
-                let _ = handle.emit_all("nyx:global-shortcut", "CmdOrCtrl+Shift+N");
+            // Load saved shortcut bindings (or the default) and register them with the OS
+            shortcuts::load_and_register_all(&app.handle());
+
+            // Start the background performance sampler
+            performance::start_sampler(app.handle());
+
+            // Start the idle-timeout watcher and wake any idle-hidden windows on window focus
+            idle::start_watcher(app.handle());
+            let idle_handle = app.handle();
+            main_window.on_window_event(move |event| {
+                if let tauri::WindowEvent::Focused(true) = event {
+                    idle::wake(&idle_handle);
+                }
+            });
+
+            // Seed the tray's "Launch" submenu with the persisted recent-apps
+            // list right away, rather than waiting on discovery.
+            let recent = recent_apps::load_and_init(&app.handle());
+            tray::refresh_apps_menu(&app.handle(), main_window_visible(&app.handle()), &recent);
+
+            // Warm the discovered-apps cache in the background so launches
+            // from the tray or the frontend's full catalog can be matched
+            // back to a name/icon for the recent-apps list.
+            let discovery_handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                let discovered_state = discovery_handle.state::<DiscoveredAppsState>();
+                let _ = app_discovery::get_desktop_apps(discovered_state).await;
             });
 
             Ok(())
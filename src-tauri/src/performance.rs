@@ -0,0 +1,89 @@
+// Real CPU/memory/process metrics, sampled in the background so the shell
+// can show a live performance readout instead of "Unknown" placeholders.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use sysinfo::{CpuExt, System, SystemExt};
+use tauri::{AppHandle, Manager};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct PerformanceState(pub Mutex<System>);
+
+impl PerformanceState {
+    pub fn new() -> Self {
+        Self(Mutex::new(System::new_all()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceInfo {
+    pub total_memory_kb: u64,
+    pub used_memory_kb: u64,
+    pub cpu_usage_percent: f32,
+    pub per_core_usage_percent: Vec<f32>,
+    pub process_count: usize,
+    pub uptime_secs: u64,
+}
+
+fn snapshot(system: &System) -> PerformanceInfo {
+    let cpus = system.cpus();
+    let per_core_usage_percent: Vec<f32> = cpus.iter().map(|cpu| cpu.cpu_usage()).collect();
+    let cpu_usage_percent = if per_core_usage_percent.is_empty() {
+        0.0
+    } else {
+        per_core_usage_percent.iter().sum::<f32>() / per_core_usage_percent.len() as f32
+    };
+
+    PerformanceInfo {
+        total_memory_kb: system.total_memory(),
+        used_memory_kb: system.used_memory(),
+        cpu_usage_percent,
+        per_core_usage_percent,
+        process_count: system.processes().len(),
+        uptime_secs: system.uptime(),
+    }
+}
+
+#[tauri::command]
+pub async fn get_performance_info(
+    state: tauri::State<'_, PerformanceState>,
+) -> Result<PerformanceInfo, String> {
+    let mut system = state.0.lock().map_err(|e| e.to_string())?;
+    system.refresh_cpu();
+    system.refresh_memory();
+    system.refresh_processes();
+    Ok(snapshot(&system))
+}
+
+/// Starts the background sampler. Must be called once from `setup()`.
+///
+/// `sysinfo` requires CPU usage to be sampled twice with a delay in between
+/// to compute a meaningful delta, so the loop refreshes once, sleeps, then
+/// refreshes and emits on every subsequent tick.
+pub fn start_sampler(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        {
+            let state = app_handle.state::<PerformanceState>();
+            let mut system = state.0.lock().expect("performance state poisoned");
+            system.refresh_cpu();
+        }
+
+        loop {
+            tokio::time::sleep(SAMPLE_INTERVAL).await;
+
+            let info = {
+                let state = app_handle.state::<PerformanceState>();
+                let mut system = state.0.lock().expect("performance state poisoned");
+                system.refresh_cpu();
+                system.refresh_memory();
+                system.refresh_processes();
+                snapshot(&system)
+            };
+
+            let _ = app_handle.emit_all("nyx:performance-tick", info);
+        }
+    });
+}
@@ -0,0 +1,73 @@
+// Tracks apps the user has recently launched, so the tray's "Launch"
+// submenu can show a short, useful list instead of the full discovery
+// dump — a real machine can have hundreds of installed apps.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager};
+
+use crate::app_discovery::DesktopApp;
+
+const CONFIG_FILE: &str = "recent_apps.json";
+const MAX_RECENT: usize = 8;
+
+pub struct RecentAppsState(Mutex<Vec<DesktopApp>>);
+
+impl RecentAppsState {
+    pub fn new() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+}
+
+fn config_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not resolve app data dir".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(CONFIG_FILE))
+}
+
+fn load_from_disk(app_handle: &AppHandle) -> Vec<DesktopApp> {
+    config_path(app_handle)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_to_disk(app_handle: &AppHandle, apps: &[DesktopApp]) -> Result<(), String> {
+    let path = config_path(app_handle)?;
+    let json = serde_json::to_string_pretty(apps).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Loads the persisted recent-apps list into `RecentAppsState`. Call once
+/// from `setup()`, after the state has been added via `.manage()`; returns
+/// the loaded list so the caller can seed the tray menu immediately.
+pub fn load_and_init(app_handle: &AppHandle) -> Vec<DesktopApp> {
+    let apps = load_from_disk(app_handle);
+    *app_handle
+        .state::<RecentAppsState>()
+        .0
+        .lock()
+        .expect("recent apps state poisoned") = apps.clone();
+    apps
+}
+
+/// Moves `app` to the front of the recent list (de-duplicating by path),
+/// trims it to `MAX_RECENT`, persists it, and returns the updated list so
+/// the caller can refresh the tray menu.
+pub fn record_launch(app_handle: &AppHandle, app: DesktopApp) -> Vec<DesktopApp> {
+    let state = app_handle.state::<RecentAppsState>();
+    let mut recent = state.0.lock().expect("recent apps state poisoned");
+
+    recent.retain(|existing| existing.path != app.path);
+    recent.insert(0, app);
+    recent.truncate(MAX_RECENT);
+
+    let _ = save_to_disk(app_handle, &recent);
+    recent.clone()
+}
@@ -0,0 +1,130 @@
+// Persistent global shortcut registry. Bindings are keyed by a stable
+// `action_id` rather than the raw accelerator, so the frontend and the
+// emitted events don't need to care which keys are currently bound.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const CONFIG_FILE: &str = "shortcuts.json";
+const DEFAULT_ACTION_ID: &str = "new-window";
+const DEFAULT_ACCELERATOR: &str = "CmdOrCtrl+Shift+N";
+
+pub struct ShortcutState(Mutex<HashMap<String, String>>);
+
+impl ShortcutState {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutBinding {
+    pub action_id: String,
+    pub accelerator: String,
+}
+
+fn config_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not resolve app data dir".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(CONFIG_FILE))
+}
+
+fn default_bindings() -> HashMap<String, String> {
+    HashMap::from([(DEFAULT_ACTION_ID.to_string(), DEFAULT_ACCELERATOR.to_string())])
+}
+
+fn load_bindings(app_handle: &AppHandle) -> HashMap<String, String> {
+    config_path(app_handle)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_else(default_bindings)
+}
+
+fn save_bindings(app_handle: &AppHandle, bindings: &HashMap<String, String>) -> Result<(), String> {
+    let path = config_path(app_handle)?;
+    let json = serde_json::to_string_pretty(bindings).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+fn register_action(app_handle: &AppHandle, action_id: &str, accelerator: &str) -> Result<(), String> {
+    let mut manager = app_handle.global_shortcut_manager();
+    let handle = app_handle.clone();
+    let action_id = action_id.to_string();
+    manager
+        .register(accelerator, move || {
+            let _ = handle.emit_all("nyx:global-shortcut", action_id.clone());
+        })
+        .map_err(|e| format!("Failed to register shortcut: {}", e))
+}
+
+/// Loads saved bindings from disk and registers each with the OS. Call once
+/// from `setup()`, after `ShortcutState` has been added via `.manage()`.
+pub fn load_and_register_all(app_handle: &AppHandle) {
+    let bindings = load_bindings(app_handle);
+    for (action_id, accelerator) in &bindings {
+        if let Err(e) = register_action(app_handle, action_id, accelerator) {
+            eprintln!("Failed to register shortcut '{}' for '{}': {}", accelerator, action_id, e);
+        }
+    }
+
+    let state = app_handle.state::<ShortcutState>();
+    *state.0.lock().expect("shortcut state poisoned") = bindings;
+}
+
+#[tauri::command]
+pub async fn list_shortcuts(state: tauri::State<'_, ShortcutState>) -> Result<Vec<ShortcutBinding>, String> {
+    let bindings = state.0.lock().map_err(|e| e.to_string())?;
+    Ok(bindings
+        .iter()
+        .map(|(action_id, accelerator)| ShortcutBinding {
+            action_id: action_id.clone(),
+            accelerator: accelerator.clone(),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn rebind_shortcut(
+    app_handle: AppHandle,
+    state: tauri::State<'_, ShortcutState>,
+    action_id: String,
+    accelerator: String,
+) -> Result<(), String> {
+    let mut bindings = state.0.lock().map_err(|e| e.to_string())?;
+
+    let manager = app_handle.global_shortcut_manager();
+    if manager.is_registered(&accelerator).unwrap_or(false)
+        && bindings.get(&action_id) != Some(&accelerator)
+    {
+        return Err(format!("'{}' is already bound to another action", accelerator));
+    }
+
+    let previous = bindings.get(&action_id).cloned();
+    if previous.as_deref() == Some(accelerator.as_str()) {
+        return Ok(());
+    }
+
+    // Register the new binding before tearing down the old one, so a failed
+    // `register_action` (bad accelerator, or a conflict `is_registered`
+    // missed) leaves the action still responding to its previous shortcut
+    // instead of silently going dead while `list_shortcuts` still reports it
+    // as bound.
+    register_action(&app_handle, &action_id, &accelerator)?;
+
+    if let Some(previous) = previous {
+        let _ = app_handle.global_shortcut_manager().unregister(&previous);
+    }
+
+    bindings.insert(action_id, accelerator);
+    save_bindings(&app_handle, &bindings)?;
+    Ok(())
+}
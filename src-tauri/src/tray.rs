@@ -0,0 +1,65 @@
+// Stateful system tray: a toggle item whose label tracks window visibility,
+// plus a "Launch" submenu of recently launched apps (see `recent_apps`) —
+// the full app-discovery catalog belongs in the frontend, not a tray menu
+// that could otherwise balloon to hundreds of entries.
+
+use tauri::{AppHandle, CustomMenuItem, Manager, SystemTrayMenu, SystemTrayMenuItem, SystemTraySubmenu};
+
+use crate::app_discovery::DesktopApp;
+
+pub const TOGGLE_VISIBILITY_ID: &str = "toggle_visibility";
+pub const QUIT_ID: &str = "quit";
+const APP_ITEM_PREFIX: &str = "tray-app:";
+
+/// `apps` should be a short, bounded list (recent/pinned), not the full
+/// discovery result — see `recent_apps`.
+pub fn build_menu(window_visible: bool, apps: &[DesktopApp]) -> SystemTrayMenu {
+    let toggle = CustomMenuItem::new(TOGGLE_VISIBILITY_ID.to_string(), toggle_label(window_visible));
+    let quit = CustomMenuItem::new(QUIT_ID.to_string(), "Quit NYX OS");
+
+    let mut menu = SystemTrayMenu::new().add_item(toggle);
+
+    if !apps.is_empty() {
+        let mut apps_menu = SystemTrayMenu::new();
+        for app in apps {
+            let id = format!("{APP_ITEM_PREFIX}{}", app.path);
+            apps_menu = apps_menu.add_item(CustomMenuItem::new(id, app.name.clone()));
+        }
+        menu = menu
+            .add_native_item(SystemTrayMenuItem::Separator)
+            .add_submenu(SystemTraySubmenu::new("Launch", apps_menu));
+    }
+
+    menu.add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(quit)
+}
+
+/// Extracts the app path from a tray menu item id, if it identifies an app
+/// in the "Launch" submenu rather than a fixed menu item.
+pub fn app_path_from_menu_id(id: &str) -> Option<&str> {
+    id.strip_prefix(APP_ITEM_PREFIX)
+}
+
+fn toggle_label(window_visible: bool) -> &'static str {
+    if window_visible {
+        "Hide NYX OS"
+    } else {
+        "Show NYX OS"
+    }
+}
+
+/// Updates the toggle item's label to reflect the main window's current
+/// visibility. Call this after every show/hide, whether triggered from the
+/// tray or from the frontend.
+pub fn set_toggle_title(app: &AppHandle, window_visible: bool) {
+    let _ = app
+        .tray_handle()
+        .get_item(TOGGLE_VISIBILITY_ID)
+        .set_title(toggle_label(window_visible));
+}
+
+/// Rebuilds the tray menu with a fresh recent-apps list, preserving the
+/// current toggle label.
+pub fn refresh_apps_menu(app: &AppHandle, window_visible: bool, apps: &[DesktopApp]) {
+    let _ = app.tray_handle().set_menu(build_menu(window_visible, apps));
+}